@@ -15,26 +15,19 @@
 //! ### Non-Streaming Completion
 //!
 //! ```no_run
-//! use ritellm::{completion, CompletionResponse, Message};
+//! use ritellm::{CompletionRequest, CompletionResponse, Message};
 //!
 //! # #[tokio::main]
 //! # async fn main() -> anyhow::Result<()> {
-//! match completion(
-//!     "openai/gpt-4o-mini".to_string(),
-//!     vec![Message {
-//!         role: "user".to_string(),
-//!         content: "What is 2+2?".to_string(),
-//!     }],
-//!     Some(0.7),
-//!     Some(50),
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//! ).await? {
+//! match CompletionRequest::builder()
+//!     .model("openai/gpt-4o-mini")
+//!     .message(Message::user("What is 2+2?"))
+//!     .temperature(0.7)
+//!     .max_tokens(50)
+//!     .build()?
+//!     .send()
+//!     .await?
+//! {
 //!     CompletionResponse::Response(response) => {
 //!         println!("Response: {}", response.choices[0].message.content);
 //!     }
@@ -47,27 +40,19 @@
 //! ### Streaming Completion
 //!
 //! ```no_run
-//! use ritellm::{completion, CompletionResponse, Message};
+//! use ritellm::{CompletionRequest, CompletionResponse, Message};
 //! use futures::StreamExt;
 //!
 //! # #[tokio::main]
 //! # async fn main() -> anyhow::Result<()> {
-//! match completion(
-//!     "openai/gpt-4o-mini".to_string(),
-//!     vec![Message {
-//!         role: "user".to_string(),
-//!         content: "Tell me a story.".to_string(),
-//!     }],
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     None,
-//!     Some(true),
-//!     None,
-//! ).await? {
+//! match CompletionRequest::builder()
+//!     .model("openai/gpt-4o-mini")
+//!     .message(Message::user("Tell me a story."))
+//!     .stream(true)
+//!     .build()?
+//!     .send()
+//!     .await?
+//! {
 //!     CompletionResponse::Stream(stream) => {
 //!         // Use stream combinators for elegant processing
 //!         stream
@@ -85,6 +70,9 @@
 //! # }
 //! ```
 //!
+//! The builder is the recommended entrypoint; [`completion`] (the ten-argument
+//! free function) remains available for existing callers.
+//!
 //! ## Environment Setup
 //!
 //! Set your OpenAI API key:
@@ -93,15 +81,26 @@
 //! ```
 
 pub mod openai;
+pub mod provider;
+pub mod registry;
+#[cfg(feature = "server")]
+pub mod server;
 
 use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
 
 // Re-export commonly used types for convenience
 pub use openai::{
-    ChatChoiceStream, ChatCompletionRequest, ChatCompletionResponse, ChatCompletionResponseStream,
-    ChatCompletionStreamResponse, ChatCompletionStreamResponseDelta, Choice, Message, Usage,
-    openai_completion, openai_completion_stream,
+    ChatChoiceStream, ChatCompletionRequest, ChatCompletionRequestBuilder, ChatCompletionResponse,
+    ChatCompletionResponseStream, ChatCompletionStreamResponse, ChatCompletionStreamResponseDelta,
+    Choice, ClientConfig, ContentPart, ImageUrl, Message, MessageContent, Prompt, Role, TextChoice,
+    TextCompletionRequest, TextCompletionResponse, TextCompletionResponseStream,
+    TextCompletionStreamChoice, TextCompletionStreamResponse, Usage, openai_completion,
+    openai_completion_stream, openai_completion_stream_with_config, openai_completion_with_config,
+    openai_text_completion, openai_text_completion_stream, openai_text_completion_stream_with_config,
+    openai_text_completion_with_config,
 };
+pub use provider::{AzureOpenAIProvider, OpenAICompatibleProvider};
 
 /// Enum representing either a complete response or a stream of response chunks
 pub enum CompletionResponse {
@@ -122,6 +121,225 @@ impl std::fmt::Debug for CompletionResponse {
     }
 }
 
+/// Starting point for [`CompletionRequestBuilder`], the ergonomic alternative
+/// to [`completion`]'s ten positional arguments.
+pub struct CompletionRequest;
+
+impl CompletionRequest {
+    /// Starts a [`CompletionRequestBuilder`].
+    pub fn builder() -> CompletionRequestBuilder {
+        CompletionRequestBuilder::default()
+    }
+}
+
+/// Fluent builder for gateway-routed completions, avoiding the long
+/// `None, None, None, ...` chains [`completion`] requires for arguments the
+/// caller doesn't care about.
+///
+/// Wraps [`ChatCompletionRequestBuilder`] so the two builders can't drift —
+/// every setter besides [`Self::message`] (which takes a whole [`Message`]
+/// rather than a `(Role, content)` pair) just delegates.
+///
+/// Call [`Self::build`] to validate the request, then [`BuiltCompletionRequest::send`]
+/// to dispatch it through the same provider-registry lookup [`completion`] uses.
+/// See the crate-level docs for a full example.
+#[derive(Debug, Default)]
+pub struct CompletionRequestBuilder(ChatCompletionRequestBuilder);
+
+impl CompletionRequestBuilder {
+    /// Sets the model, in `"<provider>/<model>"` form (e.g. `"openai/gpt-4o-mini"`).
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.0 = self.0.model(model);
+        self
+    }
+
+    /// Appends a message to the conversation.
+    pub fn message(mut self, message: Message) -> Self {
+        self.0 = self.0.message(message.role, message.content);
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.0 = self.0.temperature(temperature);
+        self
+    }
+
+    /// Sets the maximum number of tokens to generate.
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.0 = self.0.max_tokens(max_tokens);
+        self
+    }
+
+    /// Sets the nucleus sampling parameter.
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.0 = self.0.top_p(top_p);
+        self
+    }
+
+    /// Sets the frequency penalty.
+    pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Self {
+        self.0 = self.0.frequency_penalty(frequency_penalty);
+        self
+    }
+
+    /// Sets the presence penalty.
+    pub fn presence_penalty(mut self, presence_penalty: f32) -> Self {
+        self.0 = self.0.presence_penalty(presence_penalty);
+        self
+    }
+
+    /// Sets the stop sequences.
+    pub fn stop(mut self, stop: Vec<String>) -> Self {
+        self.0 = self.0.stop(stop);
+        self
+    }
+
+    /// Sets the number of completions to generate.
+    pub fn n(mut self, n: u32) -> Self {
+        self.0 = self.0.n(n);
+        self
+    }
+
+    /// Sets whether the response should be streamed.
+    pub fn stream(mut self, stream: bool) -> Self {
+        self.0 = self.0.stream(stream);
+        self
+    }
+
+    /// Sets whether to return log probabilities for each output token.
+    pub fn logprobs(mut self, logprobs: bool) -> Self {
+        self.0 = self.0.logprobs(logprobs);
+        self
+    }
+
+    /// Sets the number of most likely tokens to return alongside each output
+    /// token's log probability (0-20). Requires `.logprobs(true)`.
+    pub fn top_logprobs(mut self, top_logprobs: u8) -> Self {
+        self.0 = self.0.top_logprobs(top_logprobs);
+        self
+    }
+
+    /// Validates and finalizes the request, failing if no model was set.
+    pub fn build(self) -> Result<BuiltCompletionRequest> {
+        Ok(BuiltCompletionRequest(self.0.build()?))
+    }
+}
+
+/// A validated, ready-to-send completion request built by
+/// [`CompletionRequestBuilder::build`].
+#[derive(Debug)]
+pub struct BuiltCompletionRequest(ChatCompletionRequest);
+
+impl From<ChatCompletionRequest> for BuiltCompletionRequest {
+    fn from(request: ChatCompletionRequest) -> Self {
+        Self(request)
+    }
+}
+
+impl BuiltCompletionRequest {
+    /// Sends the request through the same provider-registry dispatch [`completion`] uses.
+    pub async fn send(self) -> Result<CompletionResponse> {
+        dispatch_completion(self.0).await
+    }
+}
+
+/// Enum representing either a complete legacy text completion response or a
+/// stream of response chunks, mirroring [`CompletionResponse`] for the
+/// `/v1/completions` prompt-in/text-out shape.
+pub enum TextCompletionOutcome {
+    /// A complete, non-streaming response
+    Response(TextCompletionResponse),
+    /// A stream of response chunks
+    Stream(TextCompletionResponseStream),
+}
+
+impl std::fmt::Debug for TextCompletionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextCompletionOutcome::Response(response) => {
+                f.debug_tuple("Response").field(response).finish()
+            }
+            TextCompletionOutcome::Stream(_) => {
+                f.debug_tuple("Stream").field(&"<stream>").finish()
+            }
+        }
+    }
+}
+
+/// Unified legacy text completion function that routes to the appropriate
+/// provider based on model prefix, mirroring [`completion`] for the older
+/// prompt-string `/v1/completions` shape.
+///
+/// # Arguments
+///
+/// * `model` - The model to use, specified in format "provider/model" (e.g., "openai/gpt-3.5-turbo-instruct")
+/// * `prompt` - The prompt(s) to complete
+/// * `temperature` - Sampling temperature (0.0 to 2.0)
+/// * `max_tokens` - Maximum number of tokens to generate
+/// * `top_p` - Nucleus sampling parameter
+/// * `frequency_penalty` - Frequency penalty (-2.0 to 2.0)
+/// * `presence_penalty` - Presence penalty (-2.0 to 2.0)
+/// * `stop` - Stop sequences
+/// * `n` - Number of completions to generate
+/// * `stream` - Whether to stream the response
+///
+/// # Returns
+///
+/// * `Result<TextCompletionOutcome>` - Either a complete response or a stream, depending on the `stream` parameter
+///
+/// See [`completion`] for details on provider resolution.
+pub async fn text_completion(
+    model: String,
+    prompt: Prompt,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    top_p: Option<f32>,
+    frequency_penalty: Option<f32>,
+    presence_penalty: Option<f32>,
+    stop: Option<Vec<String>>,
+    n: Option<u32>,
+    stream: Option<bool>,
+) -> Result<TextCompletionOutcome> {
+    let mut request = TextCompletionRequest {
+        model,
+        prompt,
+        temperature,
+        max_tokens,
+        top_p,
+        frequency_penalty,
+        presence_penalty,
+        stop,
+        n,
+        stream,
+    };
+
+    let (prefix, model_name) = request
+        .model
+        .split_once('/')
+        .map(|(prefix, model_name)| (prefix.to_string(), model_name.to_string()))
+        .unwrap_or_else(|| (request.model.clone(), request.model.clone()));
+
+    let provider = registry::resolve(&prefix).with_context(|| {
+        format!(
+            "Unsupported provider '{}' in model '{}'. Register a custom provider with \
+             ritellm::registry::register_provider, or use a built-in prefix like 'openai/'.",
+            prefix, request.model
+        )
+    })?;
+    request.model = model_name;
+
+    if request.stream.unwrap_or(false) {
+        Ok(TextCompletionOutcome::Stream(
+            provider.complete_text_stream(request).await,
+        ))
+    } else {
+        Ok(TextCompletionOutcome::Response(
+            provider.complete_text(request).await?,
+        ))
+    }
+}
+
 /// Unified completion function that routes to the appropriate provider based on model prefix
 ///
 /// # Arguments
@@ -136,7 +354,6 @@ impl std::fmt::Debug for CompletionResponse {
 /// * `stop` - Stop sequences
 /// * `n` - Number of completions to generate
 /// * `stream` - Whether to stream the response
-/// * `base_url` - Custom base URL for the API endpoint
 ///
 /// # Returns
 ///
@@ -144,7 +361,16 @@ impl std::fmt::Debug for CompletionResponse {
 ///
 /// # Supported Providers
 ///
-/// * `openai/` - Routes to OpenAI API (e.g., "openai/gpt-4o", "openai/gpt-4o-mini")
+/// `completion` routes on the prefix before the first `/` in `model` using a
+/// global provider registry (see the [`registry`] module). `"openai"` is
+/// registered out of the box (e.g. `"openai/gpt-4o"`, `"openai/gpt-4o-mini"`);
+/// call [`registry::register_provider`] to add others (Anthropic, Ollama,
+/// Cohere, a self-hosted server, ...) without touching this function.
+///
+/// To point `"openai/..."` models at a proxy, relay, or self-hosted server,
+/// call [`registry::configure_openai`] with a [`ClientConfig`] that sets
+/// `api_base` before calling `completion`, or register an
+/// [`OpenAICompatibleProvider`] under its own prefix.
 ///
 /// # Environment Variables
 ///
@@ -159,10 +385,7 @@ impl std::fmt::Debug for CompletionResponse {
 /// async fn main() -> anyhow::Result<()> {
 ///     match completion(
 ///         "openai/gpt-4o-mini".to_string(),
-///         vec![Message {
-///             role: "user".to_string(),
-///             content: "Hello!".to_string(),
-///         }],
+///         vec![Message::user("Hello!")],
 ///         Some(0.7),
 ///         Some(100),
 ///         None,
@@ -171,7 +394,6 @@ impl std::fmt::Debug for CompletionResponse {
 ///         None,
 ///         None,
 ///         None,
-///         None,
 ///     ).await? {
 ///         CompletionResponse::Response(response) => {
 ///             println!("{}", response.choices[0].message.content);
@@ -194,10 +416,7 @@ impl std::fmt::Debug for CompletionResponse {
 /// async fn main() -> anyhow::Result<()> {
 ///     match completion(
 ///         "openai/gpt-4o-mini".to_string(),
-///         vec![Message {
-///             role: "user".to_string(),
-///             content: "Tell me a story.".to_string(),
-///         }],
+///         vec![Message::user("Tell me a story.")],
 ///         Some(0.7),
 ///         Some(100),
 ///         None,
@@ -206,7 +425,6 @@ impl std::fmt::Debug for CompletionResponse {
 ///         None,
 ///         None,
 ///         Some(true),
-///         None,
 ///     ).await? {
 ///         CompletionResponse::Response(response) => {
 ///             println!("{}", response.choices[0].message.content);
@@ -240,10 +458,9 @@ pub async fn completion(
     stop: Option<Vec<String>>,
     n: Option<u32>,
     stream: Option<bool>,
-    base_url: Option<String>,
 ) -> Result<CompletionResponse> {
     // Create the ChatCompletionRequest from individual parameters
-    let mut request = ChatCompletionRequest {
+    let request = ChatCompletionRequest {
         model,
         messages,
         temperature,
@@ -254,36 +471,78 @@ pub async fn completion(
         stop,
         n,
         stream,
-        base_url,
+        logprobs: None,
+        top_logprobs: None,
     };
-    // Check if model starts with "openai/"
-    if request.model.starts_with("openai/") {
-        // Strip the "openai/" prefix
-        request.model = request
-            .model
-            .strip_prefix("openai/")
-            .context("Failed to strip openai/ prefix")?
-            .to_string();
-
-        // Check if streaming is enabled
-        if request.stream.is_some() && request.stream.unwrap() {
-            // Return streaming response
-            let stream = openai_completion_stream(request).await;
-            Ok(CompletionResponse::Stream(stream))
-        } else {
-            // Return complete response
-            let response = openai_completion(request).await?;
-            Ok(CompletionResponse::Response(response))
-        }
-    } else {
-        // Return error for unsupported providers
-        anyhow::bail!(
-            "Unsupported provider in model '{}'. Currently only 'openai/' prefix is supported.",
-            request.model
+
+    dispatch_completion(request).await
+}
+
+/// Splits `request.model` on `"<prefix>/<model>"`, looks up the prefix in the
+/// provider registry, and routes the request to it. Shared by [`completion`]
+/// and [`BuiltCompletionRequest::send`] so both entrypoints resolve providers
+/// identically.
+///
+/// A model with no `/` is looked up under its full name, which simply won't
+/// resolve to a registered provider.
+async fn dispatch_completion(mut request: ChatCompletionRequest) -> Result<CompletionResponse> {
+    let (prefix, model_name) = request
+        .model
+        .split_once('/')
+        .map(|(prefix, model_name)| (prefix.to_string(), model_name.to_string()))
+        .unwrap_or_else(|| (request.model.clone(), request.model.clone()));
+
+    let provider = registry::resolve(&prefix).with_context(|| {
+        format!(
+            "Unsupported provider '{}' in model '{}'. Register a custom provider with \
+             ritellm::registry::register_provider, or use a built-in prefix like 'openai/'.",
+            prefix, request.model
         )
+    })?;
+    request.model = model_name;
+
+    if request.stream.unwrap_or(false) {
+        Ok(CompletionResponse::Stream(
+            provider.complete_stream(request).await,
+        ))
+    } else {
+        Ok(CompletionResponse::Response(
+            provider.complete(request).await?,
+        ))
     }
 }
 
+/// Default cap on the number of requests [`completion_batch`] keeps in flight
+/// at once, chosen to stay well under typical provider rate limits.
+pub const DEFAULT_MAX_CLIENT_BATCH_SIZE: usize = 4;
+
+/// Dispatches a batch of requests through [`completion`]'s provider-registry
+/// routing, running up to [`DEFAULT_MAX_CLIENT_BATCH_SIZE`] of them
+/// concurrently.
+///
+/// The output preserves the input order, and a failure in one request doesn't
+/// affect the others — each slot in the returned `Vec` holds that request's
+/// own `Result`. See [`completion_batch_with_concurrency`] to customize the
+/// concurrency cap.
+pub async fn completion_batch(requests: Vec<ChatCompletionRequest>) -> Vec<Result<CompletionResponse>> {
+    completion_batch_with_concurrency(requests, DEFAULT_MAX_CLIENT_BATCH_SIZE).await
+}
+
+/// Like [`completion_batch`], but with a caller-supplied
+/// `max_client_batch_size` instead of [`DEFAULT_MAX_CLIENT_BATCH_SIZE`].
+///
+/// A `max_client_batch_size` of 0 is treated as 1 (no concurrency).
+pub async fn completion_batch_with_concurrency(
+    requests: Vec<ChatCompletionRequest>,
+    max_client_batch_size: usize,
+) -> Vec<Result<CompletionResponse>> {
+    stream::iter(requests)
+        .map(dispatch_completion)
+        .buffered(max_client_batch_size.max(1))
+        .collect()
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -292,11 +551,7 @@ mod tests {
     async fn test_completion_unsupported_provider() {
         let result = completion(
             "anthropic/claude-3".to_string(),
-            vec![Message {
-                role: "user".to_string(),
-                content: "Hello!".to_string(),
-            }],
-            None,
+            vec![Message::user("Hello!")],
             None,
             None,
             None,
@@ -320,11 +575,7 @@ mod tests {
     async fn test_completion_no_provider() {
         let result = completion(
             "gpt-4o".to_string(),
-            vec![Message {
-                role: "user".to_string(),
-                content: "Hello!".to_string(),
-            }],
-            None,
+            vec![Message::user("Hello!")],
             None,
             None,
             None,
@@ -349,10 +600,7 @@ mod tests {
     async fn test_completion_with_openai_prefix() {
         let result = completion(
             "openai/gpt-4o-mini".to_string(),
-            vec![Message {
-                role: "user".to_string(),
-                content: "Say 'test' and nothing else.".to_string(),
-            }],
+            vec![Message::user("Say 'test' and nothing else.")],
             Some(0.0),
             Some(10),
             None,
@@ -361,7 +609,6 @@ mod tests {
             None,
             None,
             None,
-            None,
         )
         .await;
         assert!(result.is_ok());
@@ -382,10 +629,7 @@ mod tests {
     async fn test_completion_with_streaming() {
         let result = completion(
             "openai/gpt-4o-mini".to_string(),
-            vec![Message {
-                role: "user".to_string(),
-                content: "Say 'test' and nothing else.".to_string(),
-            }],
+            vec![Message::user("Say 'test' and nothing else.")],
             Some(0.0),
             Some(10),
             None,
@@ -394,7 +638,6 @@ mod tests {
             None,
             None,
             Some(true),
-            None,
         )
         .await;
         assert!(result.is_ok());
@@ -409,4 +652,112 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_text_completion_unsupported_provider() {
+        let result = text_completion(
+            "anthropic/claude-3".to_string(),
+            "Hello!".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Unsupported provider")
+        );
+    }
+
+    #[tokio::test]
+    #[ignore] // Requires API key
+    async fn test_text_completion_with_openai_prefix() {
+        let result = text_completion(
+            "openai/gpt-3.5-turbo-instruct".to_string(),
+            "Say 'test' and nothing else.".into(),
+            Some(0.0),
+            Some(10),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        assert!(result.is_ok());
+
+        match result.unwrap() {
+            TextCompletionOutcome::Response(response) => {
+                assert!(!response.choices.is_empty());
+                assert!(!response.choices[0].text.is_empty());
+            }
+            TextCompletionOutcome::Stream(_) => {
+                panic!("Expected Response but got Stream");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_completion_request_builder_unsupported_provider() {
+        let result = CompletionRequest::builder()
+            .model("anthropic/claude-3")
+            .message(Message::user("Hello!"))
+            .build()
+            .unwrap()
+            .send()
+            .await;
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Unsupported provider")
+        );
+    }
+
+    #[test]
+    fn test_completion_request_builder_requires_model() {
+        let result = CompletionRequest::builder()
+            .message(Message::user("Hello!"))
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_completion_batch_preserves_order_and_isolates_errors() {
+        let requests = vec![
+            ChatCompletionRequest::builder()
+                .model("anthropic/claude-3")
+                .message(Role::User, "Hello!")
+                .build()
+                .unwrap(),
+            ChatCompletionRequest::builder()
+                .model("gpt-4o")
+                .message(Role::User, "Hello!")
+                .build()
+                .unwrap(),
+        ];
+
+        let results = completion_batch(requests).await;
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert!(result.is_err());
+            assert!(
+                result
+                    .as_ref()
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Unsupported provider")
+            );
+        }
+    }
 }
@@ -1,51 +1,361 @@
 use anyhow::{Context, Result};
 use futures::Stream;
+use rand::Rng;
 use reqwest::Client;
 use reqwest_eventsource::{Event, EventSource, RequestBuilderExt};
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::pin::Pin;
 
+/// Role of the author of a chat message
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    System,
+    User,
+    Assistant,
+    Tool,
+}
+
+/// A single part of a multimodal message's content.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    /// A plain text segment.
+    Text {
+        text: String,
+    },
+    /// An image, referenced either by an `https://` URL or a
+    /// `data:image/...;base64,...` payload.
+    ImageUrl {
+        image_url: ImageUrl,
+    },
+}
+
+/// An image reference used by [`ContentPart::ImageUrl`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImageUrl {
+    pub url: String,
+}
+
+/// Content of a chat message.
+///
+/// Serializes as a bare string (the historical, backward-compatible shape)
+/// when it's plain text, or as an array of typed parts for multimodal
+/// messages sent to vision-capable models.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+impl MessageContent {
+    /// Flattens the content to its text, concatenating any text parts and
+    /// ignoring image parts. Useful for logging or display.
+    pub fn as_text(&self) -> String {
+        match self {
+            MessageContent::Text(text) => text.clone(),
+            MessageContent::Parts(parts) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    ContentPart::Text { text } => Some(text.as_str()),
+                    ContentPart::ImageUrl { .. } => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if the content has no text (image-only parts count as empty).
+    pub fn is_empty(&self) -> bool {
+        self.as_text().is_empty()
+    }
+}
+
+impl std::fmt::Display for MessageContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_text())
+    }
+}
+
+impl From<String> for MessageContent {
+    fn from(value: String) -> Self {
+        MessageContent::Text(value)
+    }
+}
+
+impl From<&str> for MessageContent {
+    fn from(value: &str) -> Self {
+        MessageContent::Text(value.to_string())
+    }
+}
+
 /// Message structure for chat completions
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Message {
-    pub role: String,
-    pub content: String,
+    pub role: Role,
+    pub content: MessageContent,
+}
+
+impl Message {
+    /// Creates a `system` message
+    pub fn system(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: Role::System,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a `user` message
+    pub fn user(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: Role::User,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a `user` message combining a text prompt with an image, for
+    /// vision-capable models. `image_url` may be an `https://` link or a
+    /// `data:image/...;base64,...` payload.
+    pub fn user_with_image(text: impl Into<String>, image_url: impl Into<String>) -> Self {
+        Self {
+            role: Role::User,
+            content: MessageContent::Parts(vec![
+                ContentPart::Text { text: text.into() },
+                ContentPart::ImageUrl {
+                    image_url: ImageUrl {
+                        url: image_url.into(),
+                    },
+                },
+            ]),
+        }
+    }
+
+    /// Creates an `assistant` message
+    pub fn assistant(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: Role::Assistant,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a `tool` message
+    pub fn tool(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: Role::Tool,
+            content: content.into(),
+        }
+    }
 }
 
 /// Request structure for OpenAI chat completions
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ChatCompletionRequest {
     pub model: String,
     pub messages: Vec<Message>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frequency_penalty: Option<f32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub presence_penalty: Option<f32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stop: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub n: Option<u32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
+    /// Whether to return log probabilities for each output token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<bool>,
+    /// Number of most likely tokens to return alongside each output token's
+    /// log probability, 0-20. Requires `logprobs: Some(true)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_logprobs: Option<u8>,
+}
+
+impl ChatCompletionRequest {
+    /// Starts a [`ChatCompletionRequestBuilder`] for constructing a request
+    /// without spelling out every optional field as `None`.
+    pub fn builder() -> ChatCompletionRequestBuilder {
+        ChatCompletionRequestBuilder::default()
+    }
+}
+
+/// Fluent builder for [`ChatCompletionRequest`], defaulting every optional
+/// field to `None` so callers only set what they need.
+///
+/// # Example
+///
+/// ```no_run
+/// use ritellm::openai::{ChatCompletionRequest, Role};
+///
+/// let request = ChatCompletionRequest::builder()
+///     .model("gpt-4o-mini")
+///     .message(Role::User, "What is 2+2?")
+///     .temperature(0.7)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct ChatCompletionRequestBuilder {
+    model: Option<String>,
+    messages: Vec<Message>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    top_p: Option<f32>,
+    frequency_penalty: Option<f32>,
+    presence_penalty: Option<f32>,
+    stop: Option<Vec<String>>,
+    n: Option<u32>,
+    stream: Option<bool>,
+    logprobs: Option<bool>,
+    top_logprobs: Option<u8>,
+}
+
+impl ChatCompletionRequestBuilder {
+    /// Sets the model name.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Appends a message with the given role and content.
+    pub fn message(mut self, role: Role, content: impl Into<MessageContent>) -> Self {
+        self.messages.push(Message {
+            role,
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Sets the sampling temperature.
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Sets the maximum number of tokens to generate.
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Sets the nucleus sampling parameter.
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// Sets the frequency penalty.
+    pub fn frequency_penalty(mut self, frequency_penalty: f32) -> Self {
+        self.frequency_penalty = Some(frequency_penalty);
+        self
+    }
+
+    /// Sets the presence penalty.
+    pub fn presence_penalty(mut self, presence_penalty: f32) -> Self {
+        self.presence_penalty = Some(presence_penalty);
+        self
+    }
+
+    /// Sets the stop sequences.
+    pub fn stop(mut self, stop: Vec<String>) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Sets the number of completions to generate.
+    pub fn n(mut self, n: u32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Sets whether the response should be streamed.
+    pub fn stream(mut self, stream: bool) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    /// Sets whether to return log probabilities for each output token.
+    pub fn logprobs(mut self, logprobs: bool) -> Self {
+        self.logprobs = Some(logprobs);
+        self
+    }
+
+    /// Sets the number of most likely tokens to return alongside each output
+    /// token's log probability (0-20). Requires `.logprobs(true)`.
+    pub fn top_logprobs(mut self, top_logprobs: u8) -> Self {
+        self.top_logprobs = Some(top_logprobs);
+        self
+    }
+
+    /// Builds the [`ChatCompletionRequest`], failing if no model was set.
+    pub fn build(self) -> Result<ChatCompletionRequest> {
+        Ok(ChatCompletionRequest {
+            model: self.model.context("model is required")?,
+            messages: self.messages,
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+            top_p: self.top_p,
+            frequency_penalty: self.frequency_penalty,
+            presence_penalty: self.presence_penalty,
+            stop: self.stop,
+            n: self.n,
+            logprobs: self.logprobs,
+            top_logprobs: self.top_logprobs,
+            stream: self.stream,
+        })
+    }
 }
 
 /// Choice structure in the response
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Choice {
     pub index: u32,
     pub message: Message,
     pub finish_reason: Option<String>,
+    /// Log probability information, present when the request set `logprobs: Some(true)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<Logprobs>,
+}
+
+/// Log probability information for a response choice, matching the shape of
+/// OpenAI's `choice.logprobs` object.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Logprobs {
+    /// Per-token log probability entries for the message content.
+    pub content: Vec<LogprobEntry>,
+    /// Per-token log probability entries for the refusal message, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<Vec<LogprobEntry>>,
+}
+
+/// Log probability information for a single output token.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogprobEntry {
+    pub token: String,
+    pub logprob: f32,
+    /// UTF-8 byte values of the token, or `None` when it can't be represented as bytes.
+    pub bytes: Option<Vec<u8>>,
+    pub top_logprobs: Vec<TopLogprob>,
+}
+
+/// One of the most likely alternative tokens considered at a given position.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TopLogprob {
+    pub token: String,
+    pub logprob: f32,
+    /// UTF-8 byte values of the token, or `None` when it can't be represented as bytes.
+    pub bytes: Option<Vec<u8>>,
 }
 
 /// Usage statistics in the response
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Usage {
     pub prompt_tokens: u32,
     pub completion_tokens: u32,
@@ -53,7 +363,7 @@ pub struct Usage {
 }
 
 /// Response structure from OpenAI chat completions
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ChatCompletionResponse {
     pub id: String,
     pub object: String,
@@ -90,7 +400,7 @@ pub struct ChatCompletionStreamResponseDelta {
 }
 
 /// Choice structure for streaming responses
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChatChoiceStream {
     /// The index of the choice
     pub index: u32,
@@ -98,10 +408,14 @@ pub struct ChatChoiceStream {
     pub delta: ChatCompletionStreamResponseDelta,
     /// The reason the model stopped generating tokens (only in final chunk)
     pub finish_reason: Option<String>,
+    /// Log probability information for this chunk, present when the request
+    /// set `logprobs: Some(true)`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<Logprobs>,
 }
 
 /// Streaming response chunk from OpenAI chat completions
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ChatCompletionStreamResponse {
     /// Unique identifier for the chat completion (same across all chunks)
     pub id: String,
@@ -119,6 +433,110 @@ pub struct ChatCompletionStreamResponse {
 pub type ChatCompletionResponseStream =
     Pin<Box<dyn Stream<Item = Result<ChatCompletionStreamResponse>> + Send>>;
 
+/// Per-client configuration for talking to the OpenAI API (or a compatible relay).
+///
+/// All fields are optional and fall back to the historical defaults
+/// (`OPENAI_API_KEY` env var, `https://api.openai.com/v1`, no proxy, no timeout)
+/// when left unset, so existing callers of [`openai_completion`] are unaffected.
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// API key to use instead of the `OPENAI_API_KEY` environment variable.
+    pub api_key: Option<String>,
+    /// Base URL to use instead of `https://api.openai.com/v1`, e.g. to point at
+    /// a proxy, relay, or local server such as Ollama/vLLM.
+    pub api_base: Option<String>,
+    /// Value for the `OpenAI-Organization` header, if the account belongs to
+    /// more than one organization.
+    pub organization_id: Option<String>,
+    /// HTTP or SOCKS5 proxy URL (e.g. `"socks5://127.0.0.1:1080"`), applied to
+    /// all outgoing requests.
+    pub proxy: Option<String>,
+    /// Connection timeout, in seconds.
+    pub connect_timeout: Option<u64>,
+    /// Maximum number of retries for non-streaming chat completion requests
+    /// ([`openai_completion_with_config`]) on HTTP 429 or 5xx responses.
+    /// Defaults to 3; set to `Some(0)` to disable retries entirely. Legacy text
+    /// completions and streaming requests are sent once and never retried.
+    pub max_retries: Option<u32>,
+    /// Base delay for exponential backoff between retries. Defaults to 500ms.
+    pub base_delay: Option<std::time::Duration>,
+    /// Upper bound on the backoff delay between retries. Defaults to 30s.
+    pub max_delay: Option<std::time::Duration>,
+}
+
+impl ClientConfig {
+    fn resolve_api_key(&self) -> Result<String> {
+        if let Some(api_key) = &self.api_key {
+            return Ok(api_key.clone());
+        }
+        env::var("OPENAI_API_KEY").context("OPENAI_API_KEY environment variable not set")
+    }
+
+    fn resolve_base_url(&self) -> String {
+        self.api_base
+            .clone()
+            .unwrap_or_else(|| "https://api.openai.com/v1".to_string())
+    }
+
+    fn build_client(&self) -> Result<Client> {
+        let mut builder = Client::builder();
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy).context("Invalid proxy URL")?);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(std::time::Duration::from_secs(connect_timeout));
+        }
+        builder.build().context("Failed to build HTTP client")
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or(3)
+    }
+
+    fn base_delay(&self) -> std::time::Duration {
+        self.base_delay
+            .unwrap_or(std::time::Duration::from_millis(500))
+    }
+
+    fn max_delay(&self) -> std::time::Duration {
+        self.max_delay.unwrap_or(std::time::Duration::from_secs(30))
+    }
+}
+
+/// Returns `true` if an HTTP status code is worth retrying (rate limited or a
+/// transient server error).
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Computes how long to wait before the next retry attempt, honoring a
+/// `Retry-After` header (either a number of seconds or an HTTP-date) when
+/// present, otherwise falling back to `base_delay * 2^attempt` with up to 20%
+/// jitter, capped at `max_delay`.
+fn retry_delay(
+    attempt: u32,
+    retry_after: Option<&reqwest::header::HeaderValue>,
+    base_delay: std::time::Duration,
+    max_delay: std::time::Duration,
+) -> std::time::Duration {
+    if let Some(value) = retry_after.and_then(|v| v.to_str().ok()) {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return std::time::Duration::from_secs(seconds).min(max_delay);
+        }
+        if let Ok(date) = httpdate::parse_http_date(value) {
+            if let Ok(wait) = date.duration_since(std::time::SystemTime::now()) {
+                return wait.min(max_delay);
+            }
+            return std::time::Duration::ZERO;
+        }
+    }
+
+    let exponential = base_delay.saturating_mul(1 << attempt.min(20));
+    let jitter_factor = 1.0 + rand::thread_rng().gen_range(0.0..0.2);
+    let jittered = exponential.mul_f64(jitter_factor);
+    jittered.min(max_delay)
+}
+
 /// Creates a chat completion using the OpenAI API
 ///
 /// # Arguments
@@ -143,10 +561,7 @@ pub type ChatCompletionResponseStream =
 ///     let request = ChatCompletionRequest {
 ///         model: "gpt-4o".to_string(),
 ///         messages: vec![
-///             Message {
-///                 role: "user".to_string(),
-///                 content: "Hello, how are you?".to_string(),
-///             }
+///             Message::user("Hello, how are you?")
 ///         ],
 ///         temperature: Some(0.7),
 ///         max_tokens: Some(100),
@@ -156,6 +571,8 @@ pub type ChatCompletionResponseStream =
 ///         stop: None,
 ///         n: None,
 ///         stream: None,
+///         logprobs: None,
+///         top_logprobs: None,
 ///     };
 ///
 ///     let response = openai_completion(request).await?;
@@ -163,32 +580,182 @@ pub type ChatCompletionResponseStream =
 ///     Ok(())
 /// }
 /// ```
+/// Per-client configuration for talking to the OpenAI API (or a compatible relay).
+///
+/// All fields are optional and fall back to the historical defaults
+/// (`OPENAI_API_KEY` env var, `https://api.openai.com/v1`, no proxy, no timeout)
+/// when left unset, so existing callers of [`openai_completion`] are unaffected.
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// API key to use instead of the `OPENAI_API_KEY` environment variable.
+    pub api_key: Option<String>,
+    /// Base URL to use instead of `https://api.openai.com/v1`, e.g. to point at
+    /// a proxy, relay, or local server such as Ollama/vLLM.
+    pub api_base: Option<String>,
+    /// Value for the `OpenAI-Organization` header, if the account belongs to
+    /// more than one organization.
+    pub organization_id: Option<String>,
+    /// HTTP or SOCKS5 proxy URL (e.g. `"socks5://127.0.0.1:1080"`), applied to
+    /// all outgoing requests.
+    pub proxy: Option<String>,
+    /// Connection timeout, in seconds.
+    pub connect_timeout: Option<u64>,
+    /// Maximum number of retries for non-streaming requests on HTTP 429 or 5xx
+    /// responses. Defaults to 3; set to `Some(0)` to disable retries entirely.
+    pub max_retries: Option<u32>,
+    /// Base delay for exponential backoff between retries. Defaults to 500ms.
+    pub base_delay: Option<std::time::Duration>,
+    /// Upper bound on the backoff delay between retries. Defaults to 30s.
+    pub max_delay: Option<std::time::Duration>,
+}
+
+impl ClientConfig {
+    fn resolve_api_key(&self) -> Result<String> {
+        if let Some(api_key) = &self.api_key {
+            return Ok(api_key.clone());
+        }
+        env::var("OPENAI_API_KEY").context("OPENAI_API_KEY environment variable not set")
+    }
+
+    fn resolve_base_url(&self) -> String {
+        self.api_base
+            .clone()
+            .unwrap_or_else(|| "https://api.openai.com/v1".to_string())
+    }
+
+    fn build_client(&self) -> Result<Client> {
+        let mut builder = Client::builder();
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy).context("Invalid proxy URL")?);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(std::time::Duration::from_secs(connect_timeout));
+        }
+        builder.build().context("Failed to build HTTP client")
+    }
+
+    fn max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or(3)
+    }
+
+    fn base_delay(&self) -> std::time::Duration {
+        self.base_delay
+            .unwrap_or(std::time::Duration::from_millis(500))
+    }
+
+    fn max_delay(&self) -> std::time::Duration {
+        self.max_delay.unwrap_or(std::time::Duration::from_secs(30))
+    }
+}
+
+/// Returns `true` if an HTTP status code is worth retrying (rate limited or a
+/// transient server error).
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Computes how long to wait before the next retry attempt, honoring a
+/// `Retry-After` header (either a number of seconds or an HTTP-date) when
+/// present, otherwise falling back to `base_delay * 2^attempt` with up to 20%
+/// jitter, capped at `max_delay`.
+fn retry_delay(
+    attempt: u32,
+    retry_after: Option<&reqwest::header::HeaderValue>,
+    base_delay: std::time::Duration,
+    max_delay: std::time::Duration,
+) -> std::time::Duration {
+    if let Some(value) = retry_after.and_then(|v| v.to_str().ok()) {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return std::time::Duration::from_secs(seconds).min(max_delay);
+        }
+        if let Ok(date) = httpdate::parse_http_date(value) {
+            if let Ok(wait) = date.duration_since(std::time::SystemTime::now()) {
+                return wait.min(max_delay);
+            }
+            return std::time::Duration::ZERO;
+        }
+    }
+
+    let exponential = base_delay.saturating_mul(1 << attempt.min(20));
+    let jitter_factor = 1.0 + rand::thread_rng().gen_range(0.0..0.2);
+    let jittered = exponential.mul_f64(jitter_factor);
+    jittered.min(max_delay)
+}
+
 pub async fn openai_completion(request: ChatCompletionRequest) -> Result<ChatCompletionResponse> {
+    openai_completion_with_config(request, &ClientConfig::default()).await
+}
+
+/// Like [`openai_completion`], but takes a [`ClientConfig`] to override the API
+/// key, base URL, organization header, proxy, and connect timeout.
+pub async fn openai_completion_with_config(
+    request: ChatCompletionRequest,
+    config: &ClientConfig,
+) -> Result<ChatCompletionResponse> {
     // Check if stream is enabled
     if request.stream.is_some() && request.stream.unwrap() {
         anyhow::bail!("When stream is true, use openai_completion_stream instead");
     }
 
-    // Get API key from environment
-    let api_key =
-        env::var("OPENAI_API_KEY").context("OPENAI_API_KEY environment variable not set")?;
+    let api_key = config.resolve_api_key()?;
+    let url = format!("{}/chat/completions", config.resolve_base_url());
+    let client = config.build_client()?;
+
+    let max_retries = config.max_retries();
+    let base_delay = config.base_delay();
+    let max_delay = config.max_delay();
 
-    // API endpoint
-    let url = "https://api.openai.com/v1/chat/completions";
+    let mut attempt = 0;
+    loop {
+        let mut builder = client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", api_key));
+        if let Some(organization_id) = &config.organization_id {
+            builder = builder.header("OpenAI-Organization", organization_id);
+        }
+
+        let response = builder
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send request to OpenAI API")?;
 
-    // Create HTTP client
-    let client = Client::new();
+        let status = response.status();
+        if status.is_success() || !is_retryable_status(status) || attempt >= max_retries {
+            return parse_chat_completion_response(response).await;
+        }
+
+        let delay = retry_delay(attempt, response.headers().get("retry-after"), base_delay, max_delay);
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
 
+/// Sends a chat completion request using an already-configured [`reqwest::RequestBuilder`]
+/// (method, URL, and auth headers set by the caller) and extracts the response.
+///
+/// This is the execution path used by the [`crate::provider`] backends.
+/// [`openai_completion_with_config`] sends its own requests (so it can retry)
+/// and shares only the response extraction, [`parse_chat_completion_response`].
+pub(crate) async fn send_chat_completion(
+    builder: reqwest::RequestBuilder,
+    request: ChatCompletionRequest,
+) -> Result<ChatCompletionResponse> {
     // Send POST request
-    let response = client
-        .post(url)
-        .header("Authorization", format!("Bearer {}", api_key))
+    let response = builder
         .header("Content-Type", "application/json")
         .json(&request)
         .send()
         .await
         .context("Failed to send request to OpenAI API")?;
 
+    parse_chat_completion_response(response).await
+}
+
+/// Extracts a [`ChatCompletionResponse`] from a raw HTTP response, surfacing
+/// the OpenAI error payload (if present) on non-2xx statuses.
+async fn parse_chat_completion_response(response: reqwest::Response) -> Result<ChatCompletionResponse> {
     // Check if request was successful
     if response.status().is_success() {
         let completion_response: ChatCompletionResponse = response
@@ -246,10 +813,7 @@ pub async fn openai_completion(request: ChatCompletionRequest) -> Result<ChatCom
 ///     let mut request = ChatCompletionRequest {
 ///         model: "gpt-4o-mini".to_string(),
 ///         messages: vec![
-///             Message {
-///                 role: "user".to_string(),
-///                 content: "Tell me a short story.".to_string(),
-///             }
+///             Message::user("Tell me a short story.")
 ///         ],
 ///         temperature: Some(0.7),
 ///         max_tokens: Some(100),
@@ -259,6 +823,8 @@ pub async fn openai_completion(request: ChatCompletionRequest) -> Result<ChatCom
 ///         stop: None,
 ///         n: None,
 ///         stream: None,  // Will be set to true automatically
+///         logprobs: None,
+///         top_logprobs: None,
 ///     };
 ///
 ///     let mut stream = openai_completion_stream(request).await;
@@ -279,34 +845,59 @@ pub async fn openai_completion(request: ChatCompletionRequest) -> Result<ChatCom
 ///     Ok(())
 /// }
 /// ```
-pub async fn openai_completion_stream(
+pub async fn openai_completion_stream(request: ChatCompletionRequest) -> ChatCompletionResponseStream {
+    openai_completion_stream_with_config(request, &ClientConfig::default()).await
+}
+
+/// Like [`openai_completion_stream`], but takes a [`ClientConfig`] to override
+/// the API key, base URL, organization header, proxy, and connect timeout.
+pub async fn openai_completion_stream_with_config(
     mut request: ChatCompletionRequest,
+    config: &ClientConfig,
 ) -> ChatCompletionResponseStream {
     // Ensure stream is set to true
     request.stream = Some(true);
 
-    // Get API key from environment
-    let api_key = match env::var("OPENAI_API_KEY") {
+    let api_key = match config.resolve_api_key() {
         Ok(key) => key,
-        Err(_) => {
-            return Box::pin(futures::stream::once(async {
-                Err(anyhow::anyhow!(
-                    "OPENAI_API_KEY environment variable not set"
-                ))
-            }));
+        Err(e) => {
+            return Box::pin(futures::stream::once(async move { Err(e) }));
         }
     };
 
-    // API endpoint
-    let url = "https://api.openai.com/v1/chat/completions";
+    let url = format!("{}/chat/completions", config.resolve_base_url());
+    let client = match config.build_client() {
+        Ok(client) => client,
+        Err(e) => {
+            return Box::pin(futures::stream::once(async move { Err(e) }));
+        }
+    };
+
+    let mut builder = client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", api_key));
+    if let Some(organization_id) = &config.organization_id {
+        builder = builder.header("OpenAI-Organization", organization_id);
+    }
 
-    // Create HTTP client
-    let client = Client::new();
+    send_chat_completion_stream(builder, request).await
+}
+
+/// Sends a streaming chat completion request using an already-configured
+/// [`reqwest::RequestBuilder`] (method, URL, and auth headers set by the caller)
+/// and turns the resulting SSE stream into a [`ChatCompletionResponseStream`].
+///
+/// This is the shared execution path used by [`openai_completion_stream`] and by
+/// the [`crate::provider`] backends.
+pub(crate) async fn send_chat_completion_stream(
+    builder: reqwest::RequestBuilder,
+    mut request: ChatCompletionRequest,
+) -> ChatCompletionResponseStream {
+    // Ensure stream is set to true
+    request.stream = Some(true);
 
     // Build the request with EventSource support
-    let event_source = match client
-        .post(url)
-        .header("Authorization", format!("Bearer {}", api_key))
+    let event_source = match builder
         .header("Content-Type", "application/json")
         .json(&request)
         .eventsource()
@@ -320,11 +911,17 @@ pub async fn openai_completion_stream(
     };
 
     // Create the stream processing logic
-    create_stream(event_source).await
+    create_stream::<ChatCompletionStreamResponse>(event_source).await
 }
 
-/// Internal helper function to create and process the SSE stream
-async fn create_stream(mut event_source: EventSource) -> ChatCompletionResponseStream {
+/// Internal helper function to create and process an SSE stream of `T`-shaped
+/// chunks. Generic over the chunk type so it can back both chat completion
+/// streaming (`ChatCompletionStreamResponse`) and text completion streaming
+/// (`TextCompletionStreamResponse`).
+async fn create_stream<T>(mut event_source: EventSource) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+{
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
     tokio::spawn(async move {
@@ -345,9 +942,7 @@ async fn create_stream(mut event_source: EventSource) -> ChatCompletionResponseS
                         }
 
                         // Parse the JSON chunk
-                        let response = match serde_json::from_str::<ChatCompletionStreamResponse>(
-                            &message.data,
-                        ) {
+                        let response = match serde_json::from_str::<T>(&message.data) {
                             Ok(output) => Ok(output),
                             Err(e) => Err(anyhow::anyhow!(
                                 "Failed to parse stream response: {} - Data: {}",
@@ -375,6 +970,217 @@ async fn create_stream(mut event_source: EventSource) -> ChatCompletionResponseS
     Box::pin(tokio_stream::wrappers::UnboundedReceiverStream::new(rx))
 }
 
+// ============= Legacy Text Completion Types =============
+
+/// Prompt for a legacy text completion request: either a single string or a
+/// batch of strings, matching the `prompt: string | string[]` shape of the
+/// `/v1/completions` API.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum Prompt {
+    Single(String),
+    Batch(Vec<String>),
+}
+
+impl From<String> for Prompt {
+    fn from(value: String) -> Self {
+        Prompt::Single(value)
+    }
+}
+
+impl From<&str> for Prompt {
+    fn from(value: &str) -> Self {
+        Prompt::Single(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Prompt {
+    fn from(value: Vec<String>) -> Self {
+        Prompt::Batch(value)
+    }
+}
+
+/// Request structure for the legacy OpenAI `/v1/completions` endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TextCompletionRequest {
+    pub model: String,
+    pub prompt: Prompt,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+/// Choice structure in a text completion response
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TextChoice {
+    pub index: u32,
+    pub text: String,
+    pub finish_reason: Option<String>,
+}
+
+/// Response structure from the legacy OpenAI `/v1/completions` endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TextCompletionResponse {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub model: String,
+    pub choices: Vec<TextChoice>,
+    pub usage: Usage,
+}
+
+/// Choice structure for streaming text completion responses
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TextCompletionStreamChoice {
+    pub index: u32,
+    pub text: String,
+    pub finish_reason: Option<String>,
+}
+
+/// Streaming response chunk from the legacy OpenAI `/v1/completions` endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TextCompletionStreamResponse {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub model: String,
+    pub choices: Vec<TextCompletionStreamChoice>,
+}
+
+/// Type alias for the text completion streaming response
+pub type TextCompletionResponseStream =
+    Pin<Box<dyn Stream<Item = Result<TextCompletionStreamResponse>> + Send>>;
+
+/// Creates a legacy text completion using the OpenAI `/v1/completions` endpoint
+///
+/// Many OpenAI-compatible inference servers still only expose this raw
+/// prompt-in/text-out endpoint rather than `/v1/chat/completions`.
+///
+/// # Environment Variables
+///
+/// * `OPENAI_API_KEY` - Required. Your OpenAI API key
+pub async fn openai_text_completion(request: TextCompletionRequest) -> Result<TextCompletionResponse> {
+    openai_text_completion_with_config(request, &ClientConfig::default()).await
+}
+
+/// Like [`openai_text_completion`], but takes a [`ClientConfig`] to override
+/// the API key, base URL, organization header, proxy, and connect timeout.
+pub async fn openai_text_completion_with_config(
+    request: TextCompletionRequest,
+    config: &ClientConfig,
+) -> Result<TextCompletionResponse> {
+    if request.stream.is_some() && request.stream.unwrap() {
+        anyhow::bail!("When stream is true, use openai_text_completion_stream instead");
+    }
+
+    let api_key = config.resolve_api_key()?;
+    let url = format!("{}/completions", config.resolve_base_url());
+    let client = config.build_client()?;
+
+    let mut builder = client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", api_key));
+    if let Some(organization_id) = &config.organization_id {
+        builder = builder.header("OpenAI-Organization", organization_id);
+    }
+
+    let response = builder
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .send()
+        .await
+        .context("Failed to send request to OpenAI API")?;
+
+    if response.status().is_success() {
+        response
+            .json()
+            .await
+            .context("Failed to parse successful response from OpenAI API")
+    } else {
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+
+        if let Ok(error_response) = serde_json::from_str::<OpenAIError>(&error_text) {
+            anyhow::bail!(
+                "OpenAI API error ({}): {} - {}",
+                status,
+                error_response.error.error_type,
+                error_response.error.message
+            );
+        } else {
+            anyhow::bail!("OpenAI API error ({}): {}", status, error_text);
+        }
+    }
+}
+
+/// Creates a streaming legacy text completion using the OpenAI `/v1/completions` endpoint
+///
+/// # Environment Variables
+///
+/// * `OPENAI_API_KEY` - Required. Your OpenAI API key
+pub async fn openai_text_completion_stream(request: TextCompletionRequest) -> TextCompletionResponseStream {
+    openai_text_completion_stream_with_config(request, &ClientConfig::default()).await
+}
+
+/// Like [`openai_text_completion_stream`], but takes a [`ClientConfig`] to
+/// override the API key, base URL, organization header, proxy, and connect
+/// timeout.
+pub async fn openai_text_completion_stream_with_config(
+    mut request: TextCompletionRequest,
+    config: &ClientConfig,
+) -> TextCompletionResponseStream {
+    request.stream = Some(true);
+
+    let api_key = match config.resolve_api_key() {
+        Ok(key) => key,
+        Err(e) => return Box::pin(futures::stream::once(async move { Err(e) })),
+    };
+
+    let url = format!("{}/completions", config.resolve_base_url());
+    let client = match config.build_client() {
+        Ok(client) => client,
+        Err(e) => return Box::pin(futures::stream::once(async move { Err(e) })),
+    };
+
+    let mut builder = client
+        .post(url)
+        .header("Authorization", format!("Bearer {}", api_key));
+    if let Some(organization_id) = &config.organization_id {
+        builder = builder.header("OpenAI-Organization", organization_id);
+    }
+
+    let event_source = match builder
+        .header("Content-Type", "application/json")
+        .json(&request)
+        .eventsource()
+    {
+        Ok(es) => es,
+        Err(e) => {
+            return Box::pin(futures::stream::once(async move {
+                Err(anyhow::anyhow!("Failed to create event source: {}", e))
+            }));
+        }
+    };
+
+    create_stream::<TextCompletionStreamResponse>(event_source).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,10 +1189,7 @@ mod tests {
     async fn test_openai_completion() {
         let request = ChatCompletionRequest {
             model: "gpt-4o-mini".to_string(),
-            messages: vec![Message {
-                role: "user".to_string(),
-                content: "Say 'Hello, World!' and nothing else.".to_string(),
-            }],
+            messages: vec![Message::user("Say 'Hello, World!' and nothing else.")],
             temperature: Some(0.0),
             max_tokens: Some(10),
             top_p: None,
@@ -395,6 +1198,8 @@ mod tests {
             stop: None,
             n: None,
             stream: None,
+            logprobs: None,
+            top_logprobs: None,
         };
 
         let response = openai_completion(request).await;
@@ -404,4 +1209,60 @@ mod tests {
         assert!(!response.choices.is_empty());
         assert!(!response.choices[0].message.content.is_empty());
     }
+
+    #[test]
+    fn test_deserialize_chat_completion_response_with_logprobs() {
+        // Captured from a real `POST /v1/chat/completions` response with
+        // `logprobs: true, top_logprobs: 1`.
+        let payload = r#"{
+            "id": "chatcmpl-abc123",
+            "object": "chat.completion",
+            "created": 1700000000,
+            "model": "gpt-4o-mini",
+            "choices": [
+                {
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": "Hi"
+                    },
+                    "finish_reason": "stop",
+                    "logprobs": {
+                        "content": [
+                            {
+                                "token": "Hi",
+                                "logprob": -0.0003,
+                                "bytes": [72, 105],
+                                "top_logprobs": [
+                                    {
+                                        "token": "Hi",
+                                        "logprob": -0.0003,
+                                        "bytes": [72, 105]
+                                    }
+                                ]
+                            }
+                        ],
+                        "refusal": null
+                    }
+                }
+            ],
+            "usage": {
+                "prompt_tokens": 5,
+                "completion_tokens": 1,
+                "total_tokens": 6
+            }
+        }"#;
+
+        let response: ChatCompletionResponse =
+            serde_json::from_str(payload).expect("should deserialize a real logprobs payload");
+
+        let logprobs = response.choices[0]
+            .logprobs
+            .as_ref()
+            .expect("logprobs should be present");
+        assert_eq!(logprobs.content.len(), 1);
+        assert_eq!(logprobs.content[0].token, "Hi");
+        assert_eq!(logprobs.content[0].top_logprobs[0].token, "Hi");
+        assert!(logprobs.refusal.is_none());
+    }
 }
@@ -0,0 +1,133 @@
+//! OpenAI-wire-format backends for the provider [`registry`](crate::registry)
+//! that aren't the public OpenAI API itself (that one is the built-in
+//! `"openai"` prefix backed by [`crate::openai::ClientConfig`] — see
+//! [`registry::configure_openai`](crate::registry::configure_openai)).
+//!
+//! [`AzureOpenAIProvider`] and [`OpenAICompatibleProvider`] let the same
+//! [`ChatCompletionRequest`]/[`Message`] types drive other OpenAI-wire-format
+//! backends (Azure OpenAI, or any self-hosted server that speaks the OpenAI
+//! wire format) without [`completion`](crate::completion) having to know
+//! about base URLs, auth header styles, or path quirks. Each implements
+//! [`GatewayProvider`] so it can be registered under whatever prefix you
+//! like, e.g.:
+//!
+//! ```no_run
+//! use ritellm::{AzureOpenAIProvider, registry};
+//! use std::sync::Arc;
+//!
+//! registry::register_provider(
+//!     "azure",
+//!     Arc::new(AzureOpenAIProvider::new(
+//!         "api-key",
+//!         "https://my-resource.openai.azure.com",
+//!         "my-deployment",
+//!         "2024-02-01",
+//!     )),
+//! );
+//! ```
+
+use crate::openai::{
+    ChatCompletionRequest, ChatCompletionResponse, ChatCompletionResponseStream, send_chat_completion,
+    send_chat_completion_stream,
+};
+use crate::registry::GatewayProvider;
+use anyhow::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+
+/// Talks to an Azure OpenAI deployment.
+///
+/// Azure uses a different path shape (`/openai/deployments/{deployment}/chat/completions?api-version=...`)
+/// and authenticates with an `api-key` header rather than a bearer token.
+pub struct AzureOpenAIProvider {
+    api_key: String,
+    /// Resource endpoint, e.g. `https://my-resource.openai.azure.com`.
+    base_url: String,
+    deployment: String,
+    api_version: String,
+}
+
+impl AzureOpenAIProvider {
+    /// Creates a provider for a specific Azure OpenAI deployment.
+    pub fn new(
+        api_key: impl Into<String>,
+        base_url: impl Into<String>,
+        deployment: impl Into<String>,
+        api_version: impl Into<String>,
+    ) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: base_url.into(),
+            deployment: deployment.into(),
+            api_version: api_version.into(),
+        }
+    }
+
+    fn url(&self) -> String {
+        format!(
+            "{}/openai/deployments/{}/chat/completions?api-version={}",
+            self.base_url.trim_end_matches('/'),
+            self.deployment,
+            self.api_version
+        )
+    }
+}
+
+#[async_trait]
+impl GatewayProvider for AzureOpenAIProvider {
+    async fn complete(&self, request: ChatCompletionRequest) -> Result<ChatCompletionResponse> {
+        let client = Client::new();
+        let builder = client.post(self.url()).header("api-key", &self.api_key);
+        send_chat_completion(builder, request).await
+    }
+
+    async fn complete_stream(&self, request: ChatCompletionRequest) -> ChatCompletionResponseStream {
+        let client = Client::new();
+        let builder = client.post(self.url()).header("api-key", &self.api_key);
+        send_chat_completion_stream(builder, request).await
+    }
+}
+
+/// Talks to any self-hosted server that implements the OpenAI chat completions
+/// API (e.g. vLLM, Ollama's OpenAI-compatible endpoint, LM Studio).
+///
+/// The API key is optional since many self-hosted servers don't require auth.
+pub struct OpenAICompatibleProvider {
+    api_key: Option<String>,
+    base_url: String,
+}
+
+impl OpenAICompatibleProvider {
+    /// Creates a provider pointed at a self-hosted OpenAI-compatible server.
+    pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Self {
+        Self {
+            api_key,
+            base_url: base_url.into(),
+        }
+    }
+
+    fn url(&self) -> String {
+        format!("{}/chat/completions", self.base_url.trim_end_matches('/'))
+    }
+}
+
+#[async_trait]
+impl GatewayProvider for OpenAICompatibleProvider {
+    async fn complete(&self, request: ChatCompletionRequest) -> Result<ChatCompletionResponse> {
+        let client = Client::new();
+        let mut builder = client.post(self.url());
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("Bearer {}", api_key));
+        }
+        send_chat_completion(builder, request).await
+    }
+
+    async fn complete_stream(&self, request: ChatCompletionRequest) -> ChatCompletionResponseStream {
+        let client = Client::new();
+        let mut builder = client.post(self.url());
+        if let Some(api_key) = &self.api_key {
+            builder = builder.header("Authorization", format!("Bearer {}", api_key));
+        }
+        send_chat_completion_stream(builder, request).await
+    }
+}
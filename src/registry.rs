@@ -0,0 +1,165 @@
+//! A registry of gateway backends keyed by model prefix.
+//!
+//! [`completion`](crate::completion) splits a `"<provider>/<model>"` string on
+//! the first `/` and looks up the provider side in this registry, so adding a
+//! new backend (Claude, Gemini, Ollama, a self-hosted server, ...) is a matter
+//! of implementing [`GatewayProvider`] and calling [`register_provider`]
+//! rather than editing the router itself. [`crate::provider`] ships
+//! [`GatewayProvider`] implementations for the common OpenAI-wire-format
+//! backends (Azure OpenAI, self-hosted OpenAI-compatible servers) so they can
+//! be registered the same way; the built-in `"openai"` prefix below is just
+//! the default registration.
+
+use crate::openai::{
+    ChatCompletionRequest, ChatCompletionResponse, ChatCompletionResponseStream, ClientConfig,
+    TextCompletionRequest, TextCompletionResponse, TextCompletionResponseStream,
+    openai_completion_stream_with_config, openai_completion_with_config, openai_text_completion_stream_with_config,
+    openai_text_completion_with_config,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A gateway backend that can serve chat completion requests for one or more
+/// model prefixes (e.g. `"anthropic"`, `"ollama"`, `"cohere"`).
+///
+/// Implementors translate the shared [`ChatCompletionRequest`]/[`Message`](crate::Message)
+/// types into their own wire format and map the response back, so
+/// [`completion`](crate::completion) doesn't need to know anything
+/// backend-specific.
+#[async_trait]
+pub trait GatewayProvider: Send + Sync {
+    /// Serves a non-streaming chat completion request.
+    async fn complete(&self, request: ChatCompletionRequest) -> Result<ChatCompletionResponse>;
+
+    /// Serves a streaming chat completion request.
+    async fn complete_stream(&self, request: ChatCompletionRequest) -> ChatCompletionResponseStream;
+
+    /// Serves a non-streaming legacy text completion request. The default
+    /// implementation errors out; override it for backends that support the
+    /// `/v1/completions` prompt-in/text-out shape.
+    async fn complete_text(&self, _request: TextCompletionRequest) -> Result<TextCompletionResponse> {
+        anyhow::bail!("This provider does not support legacy text completions")
+    }
+
+    /// Serves a streaming legacy text completion request. See [`Self::complete_text`].
+    async fn complete_text_stream(&self, _request: TextCompletionRequest) -> TextCompletionResponseStream {
+        Box::pin(futures::stream::once(async {
+            Err(anyhow::anyhow!(
+                "This provider does not support legacy text completions"
+            ))
+        }))
+    }
+}
+
+/// The built-in `"openai"` prefix, backed by [`openai_completion_with_config`]
+/// and [`openai_completion_stream_with_config`].
+struct OpenAIGatewayProvider(ClientConfig);
+
+#[async_trait]
+impl GatewayProvider for OpenAIGatewayProvider {
+    async fn complete(&self, request: ChatCompletionRequest) -> Result<ChatCompletionResponse> {
+        openai_completion_with_config(request, &self.0).await
+    }
+
+    async fn complete_stream(&self, request: ChatCompletionRequest) -> ChatCompletionResponseStream {
+        openai_completion_stream_with_config(request, &self.0).await
+    }
+
+    async fn complete_text(&self, request: TextCompletionRequest) -> Result<TextCompletionResponse> {
+        openai_text_completion_with_config(request, &self.0).await
+    }
+
+    async fn complete_text_stream(&self, request: TextCompletionRequest) -> TextCompletionResponseStream {
+        openai_text_completion_stream_with_config(request, &self.0).await
+    }
+}
+
+/// Retry behavior for the built-in `"openai"` gateway provider on HTTP 429/5xx
+/// responses. [`crate::openai::ClientConfig`] covers the full set of knobs
+/// (auth, base URL, proxy, retries, ...); `RetryConfig` exposes just the
+/// backoff piece so [`configure_openai_retries`] callers don't have to
+/// reconstruct the rest of it.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Maximum number of retry attempts after the initial request.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries.
+    pub base_delay: std::time::Duration,
+    /// Upper bound on the backoff delay between retries.
+    pub max_delay: std::time::Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+fn registry() -> &'static Mutex<HashMap<String, Arc<dyn GatewayProvider>>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<dyn GatewayProvider>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut providers: HashMap<String, Arc<dyn GatewayProvider>> = HashMap::new();
+        providers.insert(
+            "openai".to_string(),
+            Arc::new(OpenAIGatewayProvider(ClientConfig::default())),
+        );
+        Mutex::new(providers)
+    })
+}
+
+/// Re-registers the built-in `"openai"` provider with a custom
+/// [`ClientConfig`], e.g. to point `"openai/..."` models at a proxy or relay
+/// via `api_base`, or to supply an API key without the `OPENAI_API_KEY`
+/// environment variable.
+///
+/// Call this once at startup, before serving any requests through
+/// [`completion`](crate::completion). This replaces the `base_url` parameter
+/// [`completion`](crate::completion) took before it moved to registry-based
+/// provider configuration.
+pub fn configure_openai(config: ClientConfig) {
+    register_provider("openai", Arc::new(OpenAIGatewayProvider(config)));
+}
+
+/// Re-registers the built-in `"openai"` provider with custom retry behavior
+/// and every other [`ClientConfig`] setting at its default (API key from
+/// `OPENAI_API_KEY`, the public OpenAI base URL), replacing any settings from
+/// an earlier [`configure_openai`] call.
+///
+/// Call this once at startup, before serving any requests through
+/// [`completion`](crate::completion), to make rate-limit/transient-error
+/// retries more (or less) aggressive than the defaults in [`RetryConfig`].
+/// See [`configure_openai`] to override other settings (base URL, API key, ...).
+pub fn configure_openai_retries(retry: RetryConfig) {
+    configure_openai(ClientConfig {
+        max_retries: Some(retry.max_retries),
+        base_delay: Some(retry.base_delay),
+        max_delay: Some(retry.max_delay),
+        ..ClientConfig::default()
+    });
+}
+
+/// Registers a [`GatewayProvider`] under the given model prefix, so
+/// `completion("<prefix>/<model>", ...)` routes to it.
+///
+/// Registering under an existing prefix (e.g. `"openai"`) replaces it.
+pub fn register_provider(prefix: impl Into<String>, provider: Arc<dyn GatewayProvider>) {
+    registry()
+        .lock()
+        .expect("provider registry lock poisoned")
+        .insert(prefix.into(), provider);
+}
+
+/// Looks up the provider registered for a model prefix, if any.
+pub(crate) fn resolve(prefix: &str) -> Option<Arc<dyn GatewayProvider>> {
+    registry()
+        .lock()
+        .expect("provider registry lock poisoned")
+        .get(prefix)
+        .cloned()
+}
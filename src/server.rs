@@ -0,0 +1,168 @@
+//! Exposes the gateway's multi-provider [`crate::completion`] routing as an
+//! OpenAI-compatible HTTP server.
+//!
+//! Every request forwards through the same provider-registry dispatch
+//! [`crate::completion`] uses, so it inherits whatever providers are
+//! registered in [`crate::registry`] — unlike a server that talks to a single
+//! hardcoded backend, this one honors the `"<provider>/<model>"` prefix on
+//! each request. Any OpenAI SDK can point at this server as a drop-in proxy.
+//!
+//! Gated behind the `server` feature.
+
+use crate::{
+    BuiltCompletionRequest, ChatCompletionRequest, CompletionResponse, TextCompletionOutcome,
+    TextCompletionRequest, text_completion,
+};
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, Sse},
+    },
+    routing::{get, post},
+};
+use futures::StreamExt;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Configuration for the gateway HTTP server.
+#[derive(Clone, Default)]
+pub struct ServerConfig {
+    /// Model names reported by `GET /v1/models` (e.g. `"openai/gpt-4o-mini"`).
+    pub models: Vec<String>,
+}
+
+#[derive(Clone)]
+struct AppState(Arc<ServerConfig>);
+
+/// Builds the `axum` router exposing `POST /v1/chat/completions`,
+/// `POST /v1/completions`, and `GET /v1/models`.
+pub fn router(config: ServerConfig) -> Router {
+    Router::new()
+        .route("/v1/chat/completions", post(chat_completions))
+        .route("/v1/completions", post(text_completions))
+        .route("/v1/models", get(list_models))
+        .with_state(AppState(Arc::new(config)))
+}
+
+/// Starts the gateway server on `addr` (e.g. `"0.0.0.0:8000"`), shutting down
+/// gracefully on Ctrl+C.
+pub async fn serve(addr: &str, config: ServerConfig) -> Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind to {}", addr))?;
+    axum::serve(listener, router(config))
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .context("Gateway server error")?;
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+async fn chat_completions(Json(request): Json<ChatCompletionRequest>) -> Response {
+    let result = BuiltCompletionRequest::from(request).send().await;
+
+    match result {
+        Ok(CompletionResponse::Response(response)) => Json(response).into_response(),
+        Ok(CompletionResponse::Stream(stream)) => {
+            let sse_stream = stream
+                .map(|chunk| {
+                    let data = match chunk {
+                        Ok(chunk) => serde_json::to_string(&chunk)
+                            .unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e)),
+                        Err(e) => format!("{{\"error\":\"{}\"}}", e),
+                    };
+                    Ok::<_, std::convert::Infallible>(Event::default().data(data))
+                })
+                .chain(futures::stream::once(async {
+                    Ok(Event::default().data("[DONE]"))
+                }));
+
+            Sse::new(sse_stream).into_response()
+        }
+        Err(e) => (StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+    }
+}
+
+async fn text_completions(Json(request): Json<TextCompletionRequest>) -> Response {
+    let TextCompletionRequest {
+        model,
+        prompt,
+        temperature,
+        max_tokens,
+        top_p,
+        frequency_penalty,
+        presence_penalty,
+        stop,
+        n,
+        stream,
+    } = request;
+
+    let result = text_completion(
+        model,
+        prompt,
+        temperature,
+        max_tokens,
+        top_p,
+        frequency_penalty,
+        presence_penalty,
+        stop,
+        n,
+        stream,
+    )
+    .await;
+
+    match result {
+        Ok(TextCompletionOutcome::Response(response)) => Json(response).into_response(),
+        Ok(TextCompletionOutcome::Stream(stream)) => {
+            let sse_stream = stream
+                .map(|chunk| {
+                    let data = match chunk {
+                        Ok(chunk) => serde_json::to_string(&chunk)
+                            .unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e)),
+                        Err(e) => format!("{{\"error\":\"{}\"}}", e),
+                    };
+                    Ok::<_, std::convert::Infallible>(Event::default().data(data))
+                })
+                .chain(futures::stream::once(async {
+                    Ok(Event::default().data("[DONE]"))
+                }));
+
+            Sse::new(sse_stream).into_response()
+        }
+        Err(e) => (StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+    }
+}
+
+#[derive(Serialize)]
+struct ModelListResponse {
+    object: &'static str,
+    data: Vec<ModelEntry>,
+}
+
+#[derive(Serialize)]
+struct ModelEntry {
+    id: String,
+    object: &'static str,
+}
+
+async fn list_models(State(state): State<AppState>) -> Json<ModelListResponse> {
+    Json(ModelListResponse {
+        object: "list",
+        data: state
+            .0
+            .models
+            .iter()
+            .map(|id| ModelEntry {
+                id: id.clone(),
+                object: "model",
+            })
+            .collect(),
+    })
+}